@@ -1,169 +1,1395 @@
 #![allow(non_snake_case)]
 #![no_std]
-use soroban_sdk::{contract, contracttype, contractimpl, log, Env, Address, Vec, symbol_short, Symbol};
+use soroban_sdk::{contract, contracttype, contractimpl, log, Env, Address, Vec, String, symbol_short, Symbol};
 
-// Struct to store custody account details
+// Struct to store custody account details. Token positions themselves live in
+// `AssetBalance` records keyed by (owner, token); this struct only tracks the
+// account's multi-sig/insurance configuration and which tokens it holds.
 #[contracttype]
 #[derive(Clone)]
 pub struct CustodyAccount {
     pub owner: Address,
-    pub balance: i128,
+    pub held_assets: Vec<Address>,
+    pub signers: Vec<Address>,
     pub required_signatures: u32,
     pub is_insured: bool,
     pub is_active: bool,
+    pub liquidation_start_time: u64,
+    pub existential_deposit: i128,
 }
 
-// Mapping for custody accounts
+// A single token position held by a custody account
+#[contracttype]
+#[derive(Clone)]
+pub struct AssetBalance {
+    pub free: i128,
+    pub reserved: i128,
+    pub locked: i128,
+    pub unlock_timestamp: u64,
+}
+
+impl AssetBalance {
+    fn zero() -> Self {
+        AssetBalance { free: 0, reserved: 0, locked: 0, unlock_timestamp: 0 }
+    }
+}
+
+// A pending withdrawal awaiting enough signer approvals to execute
+#[contracttype]
+#[derive(Clone)]
+pub struct WithdrawalProposal {
+    pub id: u64,
+    pub account_owner: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub to: Address,
+    pub approvals: Vec<Address>,
+    pub executed: bool,
+    pub expires_at: u64,
+}
+
+// A pending request to freeze a custody account pending liquidation, subject
+// to the same asynchronous signer-approval flow as a withdrawal proposal
+#[contracttype]
+#[derive(Clone)]
+pub struct FreezeProposal {
+    pub id: u64,
+    pub account_owner: Address,
+    pub approvals: Vec<Address>,
+    pub executed: bool,
+    pub expires_at: u64,
+}
+
+// A pending request to sweep a frozen account's balances to a recovery address
+#[contracttype]
+#[derive(Clone)]
+pub struct LiquidationProposal {
+    pub id: u64,
+    pub account_owner: Address,
+    pub to: Address,
+    pub approvals: Vec<Address>,
+    pub executed: bool,
+    pub expires_at: u64,
+}
+
+// Status of an insurance claim filed against the pool
+#[contracttype]
+#[derive(Clone, PartialEq)]
+pub enum ClaimStatus {
+    Pending,
+    Approved,
+    Settled,
+    Rejected,
+}
+
+// An insurance claim filed by an insured custody account owner
+#[contracttype]
+#[derive(Clone)]
+pub struct Claim {
+    pub id: u64,
+    pub owner: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub reason: String,
+    pub approvals: Vec<Address>,
+    pub status: ClaimStatus,
+}
+
+// Mapping for custody accounts, per-asset balances, withdrawal proposals,
+// insurance pools (one per token) and insurance claims
 #[contracttype]
 pub enum CustodyBook {
-    Account(Address)
+    Account(Address),
+    AssetBalance(Address, Address),
+    Proposal(u64),
+    InsurancePool(Address),
+    Claim(u64),
+    FreezeProposal(u64),
+    LiquidationProposal(u64),
 }
 
 // Counter for total custody accounts
 const TOTAL_ACCOUNTS: Symbol = symbol_short!("TOT_ACC");
 
+// Counter used to hand out unique withdrawal proposal ids
+const PROPOSAL_COUNT: Symbol = symbol_short!("PROP_CNT");
+
+// Withdrawal proposals are only valid for this many ledger seconds
+const PROPOSAL_LIFETIME: u64 = 7 * 24 * 60 * 60;
+
+// Default minimum free balance a custody account must retain in a given asset
+// after a withdrawal; dropping below it sweeps the dust rather than leaving
+// it behind. Accounts may set their own threshold at creation instead.
+pub const DEFAULT_EXISTENTIAL_DEPOSIT: i128 = 100;
+
+// Counter used to hand out unique insurance claim ids
+const CLAIM_COUNT: Symbol = symbol_short!("CLAIM_CNT");
+
+// Premium charged on every deposit/withdrawal for insured accounts, in basis points
+const PREMIUM_BPS: i128 = 10;
+
+// Minimum time a frozen account must sit in liquidation before it can be swept
+const LIQUIDATION_TIMELOCK: u64 = 3 * 24 * 60 * 60;
+
+// Counter used to hand out unique freeze proposal ids
+const FREEZE_PROPOSAL_COUNT: Symbol = symbol_short!("FRZ_CNT");
+
+// Counter used to hand out unique liquidation proposal ids
+const LIQUIDATION_PROPOSAL_COUNT: Symbol = symbol_short!("LIQ_CNT");
+
 #[contract]
 pub struct AssetCustodyContract;
 
 #[contractimpl]
 impl AssetCustodyContract {
-    
+
     // Function to create a new custody account with multi-signature protection
     pub fn create_custody_account(
-        env: Env, 
-        owner: Address, 
+        env: Env,
+        owner: Address,
+        signers: Vec<Address>,
         required_signatures: u32,
-        insurance: bool
+        insurance: bool,
+        existential_deposit: i128
     ) -> bool {
         owner.require_auth();
-        
+
+        if existential_deposit < 0 {
+            log!(&env, "Existential deposit cannot be negative");
+            panic!("Existential deposit must be non-negative");
+        }
+
         let account_key = CustodyBook::Account(owner.clone());
-        
+
         // Check if account already exists
         let existing: Option<CustodyAccount> = env.storage().instance().get(&account_key);
-        
-        if existing.is_some() {
+
+        if let Some(existing_account) = existing {
+            if existing_account.liquidation_start_time > 0 {
+                log!(&env, "Custody account is frozen and cannot be recreated");
+                return false;
+            }
             log!(&env, "Custody account already exists for this address");
             return false;
         }
-        
+
         // Validate required signatures (minimum 2 for multi-sig)
         if required_signatures < 2 {
             log!(&env, "Multi-signature requires at least 2 signatures");
             panic!("Minimum 2 signatures required");
         }
-        
+
+        // The signer registry must actually be able to reach the threshold
+        if signers.len() < required_signatures {
+            log!(&env, "Not enough signers to ever satisfy required_signatures");
+            panic!("Signer set smaller than required signature threshold");
+        }
+
+        // Reject duplicate signers so the threshold reflects distinct co-signers
+        let mut deduped: Vec<Address> = Vec::new(&env);
+        for signer in signers.iter() {
+            if deduped.contains(&signer) {
+                log!(&env, "Signer set contains a duplicate address");
+                panic!("Signers must be distinct addresses");
+            }
+            deduped.push_back(signer);
+        }
+
         // Create new custody account
         let new_account = CustodyAccount {
             owner: owner.clone(),
-            balance: 0,
+            held_assets: Vec::new(&env),
+            signers,
             required_signatures,
             is_insured: insurance,
             is_active: true,
+            liquidation_start_time: 0,
+            existential_deposit,
         };
-        
+
         // Store the account
         env.storage().instance().set(&account_key, &new_account);
-        
+
         // Update total accounts counter
         let mut total: u64 = env.storage().instance().get(&TOTAL_ACCOUNTS).unwrap_or(0);
         total += 1;
         env.storage().instance().set(&TOTAL_ACCOUNTS, &total);
-        
+
         env.storage().instance().extend_ttl(5000, 5000);
-        
+
         log!(&env, "Custody account created successfully for owner");
         true
     }
-    
+
+    // Explicitly register a token with a custody account so it shows up in
+    // enumeration even before the first deposit
+    pub fn register_asset(env: Env, owner: Address, token: Address) -> bool {
+        owner.require_auth();
+
+        let account_key = CustodyBook::Account(owner.clone());
+        let mut account: CustodyAccount = env.storage().instance()
+            .get(&account_key)
+            .unwrap_or_else(|| panic!("Custody account not found"));
+
+        if account.held_assets.contains(&token) {
+            log!(&env, "Asset already registered for this custody account");
+            return false;
+        }
+
+        account.held_assets.push_back(token.clone());
+        env.storage().instance().set(&account_key, &account);
+
+        let balance_key = CustodyBook::AssetBalance(owner, token);
+        env.storage().instance().set(&balance_key, &AssetBalance::zero());
+        env.storage().instance().extend_ttl(5000, 5000);
+
+        true
+    }
+
     // Function to deposit assets into custody
-    pub fn deposit_assets(env: Env, owner: Address, amount: i128) -> bool {
+    pub fn deposit_assets(env: Env, owner: Address, token: Address, amount: i128) -> bool {
         owner.require_auth();
-        
+
         if amount <= 0 {
             log!(&env, "Deposit amount must be positive");
             return false;
         }
-        
+
         let account_key = CustodyBook::Account(owner.clone());
         let mut account: CustodyAccount = env.storage().instance()
             .get(&account_key)
             .unwrap_or_else(|| panic!("Custody account not found"));
-        
+
         if !account.is_active {
             log!(&env, "Custody account is not active");
             return false;
         }
-        
-        // Update balance
-        account.balance += amount;
-        
-        // Store updated account
-        env.storage().instance().set(&account_key, &account);
+
+        if account.liquidation_start_time > 0 {
+            log!(&env, "Custody account is frozen for liquidation");
+            return false;
+        }
+
+        if Self::track_asset(&env, &mut account, &token) {
+            env.storage().instance().set(&account_key, &account);
+        }
+
+        let balance_key = CustodyBook::AssetBalance(owner, token.clone());
+        let mut balance: AssetBalance = env.storage().instance()
+            .get(&balance_key)
+            .unwrap_or_else(AssetBalance::zero);
+
+        // Insured accounts pay a basis-point premium into that token's
+        // insurance pool on every deposit; the net amount lands in the free balance
+        let credited = if account.is_insured {
+            let premium = Self::premium_amount(amount);
+            Self::accrue_premium(&env, &token, premium);
+            amount - premium
+        } else {
+            amount
+        };
+
+        balance.free += credited;
+        env.storage().instance().set(&balance_key, &balance);
         env.storage().instance().extend_ttl(5000, 5000);
-        
-        log!(&env, "Assets deposited successfully. New balance: {}", account.balance);
+
+        log!(&env, "Assets deposited successfully. New balance: {}", balance.free);
         true
     }
-    
-    // Function to withdraw assets with multi-signature verification
-    pub fn withdraw_assets(
-        env: Env, 
-        owner: Address, 
-        amount: i128,
-        signatures_count: u32
-    ) -> bool {
+
+    // Propose a withdrawal for the account's signers to approve. Nothing is
+    // debited until `execute_withdrawal` runs against a fully-approved proposal.
+    pub fn propose_withdrawal(env: Env, owner: Address, token: Address, amount: i128, to: Address) -> u64 {
         owner.require_auth();
-        
+
         if amount <= 0 {
-            log!(&env, "Withdrawal amount must be positive");
-            return false;
+            panic!("Withdrawal amount must be positive");
         }
-        
+
         let account_key = CustodyBook::Account(owner.clone());
+        let account: CustodyAccount = env.storage().instance()
+            .get(&account_key)
+            .unwrap_or_else(|| panic!("Custody account not found"));
+
+        if !account.is_active {
+            panic!("Custody account is not active");
+        }
+
+        let mut proposal_id: u64 = env.storage().instance().get(&PROPOSAL_COUNT).unwrap_or(0);
+        proposal_id += 1;
+        env.storage().instance().set(&PROPOSAL_COUNT, &proposal_id);
+
+        let proposal = WithdrawalProposal {
+            id: proposal_id,
+            account_owner: owner.clone(),
+            token,
+            amount,
+            to,
+            approvals: Vec::new(&env),
+            executed: false,
+            expires_at: env.ledger().timestamp() + PROPOSAL_LIFETIME,
+        };
+
+        env.storage().instance().set(&CustodyBook::Proposal(proposal_id), &proposal);
+        env.storage().instance().extend_ttl(5000, 5000);
+
+        log!(&env, "Withdrawal proposal {} created", proposal_id);
+        proposal_id
+    }
+
+    // A registered signer approves a pending withdrawal proposal
+    pub fn approve_withdrawal(env: Env, signer: Address, proposal_id: u64) {
+        signer.require_auth();
+
+        let proposal_key = CustodyBook::Proposal(proposal_id);
+        let mut proposal: WithdrawalProposal = env.storage().instance()
+            .get(&proposal_key)
+            .unwrap_or_else(|| panic!("Withdrawal proposal not found"));
+
+        if proposal.executed {
+            panic!("Withdrawal proposal already executed");
+        }
+
+        if env.ledger().timestamp() >= proposal.expires_at {
+            panic!("Withdrawal proposal has expired");
+        }
+
+        let account_key = CustodyBook::Account(proposal.account_owner.clone());
+        let account: CustodyAccount = env.storage().instance()
+            .get(&account_key)
+            .unwrap_or_else(|| panic!("Custody account not found"));
+
+        if !account.signers.contains(&signer) {
+            panic!("Signer is not authorized for this custody account");
+        }
+
+        if proposal.approvals.contains(&signer) {
+            panic!("Signer has already approved this proposal");
+        }
+
+        proposal.approvals.push_back(signer);
+        env.storage().instance().set(&proposal_key, &proposal);
+        env.storage().instance().extend_ttl(5000, 5000);
+
+        log!(&env, "Withdrawal proposal {} now has {} approvals", proposal_id, proposal.approvals.len());
+    }
+
+    // Execute a withdrawal proposal once it has gathered enough signer approvals
+    pub fn execute_withdrawal(env: Env, proposal_id: u64) -> bool {
+        let proposal_key = CustodyBook::Proposal(proposal_id);
+        let mut proposal: WithdrawalProposal = env.storage().instance()
+            .get(&proposal_key)
+            .unwrap_or_else(|| panic!("Withdrawal proposal not found"));
+
+        if proposal.executed {
+            log!(&env, "Withdrawal proposal already executed");
+            return false;
+        }
+
+        if env.ledger().timestamp() >= proposal.expires_at {
+            log!(&env, "Withdrawal proposal has expired");
+            return false;
+        }
+
+        let account_key = CustodyBook::Account(proposal.account_owner.clone());
         let mut account: CustodyAccount = env.storage().instance()
             .get(&account_key)
             .unwrap_or_else(|| panic!("Custody account not found"));
-        
+
         if !account.is_active {
             log!(&env, "Custody account is not active");
             return false;
         }
-        
-        // Verify multi-signature requirement
-        if signatures_count < account.required_signatures {
-            log!(&env, "Insufficient signatures for withdrawal. Required: {}, Provided: {}", 
-                account.required_signatures, signatures_count);
-            panic!("Multi-signature verification failed");
+
+        if proposal.approvals.len() < account.required_signatures {
+            log!(&env, "Insufficient approvals. Required: {}, Got: {}",
+                account.required_signatures, proposal.approvals.len());
+            return false;
         }
-        
-        // Check sufficient balance
-        if account.balance < amount {
+
+        let balance_key = CustodyBook::AssetBalance(proposal.account_owner.clone(), proposal.token.clone());
+        let mut balance: AssetBalance = env.storage().instance()
+            .get(&balance_key)
+            .unwrap_or_else(|| panic!("Asset not held by this custody account"));
+
+        // Insured accounts pay a basis-point premium into that token's
+        // insurance pool on every withdrawal, on top of the recipient's amount
+        let premium = if account.is_insured { Self::premium_amount(proposal.amount) } else { 0 };
+        let total_debit = proposal.amount + premium;
+
+        if balance.free < total_debit {
             log!(&env, "Insufficient balance for withdrawal");
             return false;
         }
-        
-        // Update balance
-        account.balance -= amount;
-        
-        // Store updated account
-        env.storage().instance().set(&account_key, &account);
+
+        balance.free -= total_debit;
+        proposal.executed = true;
+        if premium > 0 {
+            Self::accrue_premium(&env, &proposal.token, premium);
+        }
+
+        // Reap dust: a free balance left under the existential deposit is
+        // swept, and the token is dropped from the account's held list unless
+        // it still has reserved or locked funds. Earlier this instead
+        // deactivated the whole account (is_active = false); once balances
+        // became per-token, that flattened every other token's access to a
+        // single dusted one, so it was scoped down to just this asset. An
+        // account is never auto-deactivated by a dust event now — only
+        // execute_freeze touches is_active.
+        if balance.free > 0 && balance.free < account.existential_deposit {
+            log!(&env, "Remaining balance {} is below existential deposit, sweeping dust", balance.free);
+            balance.free = 0;
+            if balance.reserved == 0 && balance.locked == 0 {
+                if let Some(index) = account.held_assets.iter().position(|held| held == proposal.token) {
+                    let _ = account.held_assets.remove(index as u32);
+                    env.storage().instance().set(&account_key, &account);
+                }
+            }
+        }
+
+        env.storage().instance().set(&balance_key, &balance);
+        env.storage().instance().set(&proposal_key, &proposal);
+        env.storage().instance().extend_ttl(5000, 5000);
+
+        log!(&env, "Withdrawal proposal {} executed. Remaining balance: {}", proposal_id, balance.free);
+        true
+    }
+
+    // Move free balance into the reserved bucket, holding it against a pending
+    // obligation without making it spendable via withdrawal proposals
+    pub fn reserve(env: Env, owner: Address, token: Address, amount: i128) -> bool {
+        owner.require_auth();
+
+        if amount <= 0 {
+            log!(&env, "Reserve amount must be positive");
+            return false;
+        }
+
+        let account: CustodyAccount = env.storage().instance()
+            .get(&CustodyBook::Account(owner.clone()))
+            .unwrap_or_else(|| panic!("Custody account not found"));
+
+        if !account.is_active {
+            log!(&env, "Custody account is not active");
+            return false;
+        }
+
+        let balance_key = CustodyBook::AssetBalance(owner, token);
+        let mut balance: AssetBalance = env.storage().instance()
+            .get(&balance_key)
+            .unwrap_or_else(|| panic!("Asset not held by this custody account"));
+
+        if balance.free < amount {
+            log!(&env, "Insufficient free balance to reserve");
+            return false;
+        }
+
+        balance.free -= amount;
+        balance.reserved += amount;
+
+        env.storage().instance().set(&balance_key, &balance);
+        env.storage().instance().extend_ttl(5000, 5000);
+
+        log!(&env, "Reserved {}. Free balance: {}", amount, balance.free);
+        true
+    }
+
+    // Release reserved balance back into the free, spendable balance
+    pub fn unreserve(env: Env, owner: Address, token: Address, amount: i128) -> bool {
+        owner.require_auth();
+
+        if amount <= 0 {
+            log!(&env, "Unreserve amount must be positive");
+            return false;
+        }
+
+        let account: CustodyAccount = env.storage().instance()
+            .get(&CustodyBook::Account(owner.clone()))
+            .unwrap_or_else(|| panic!("Custody account not found"));
+
+        if !account.is_active {
+            log!(&env, "Custody account is not active");
+            return false;
+        }
+
+        let balance_key = CustodyBook::AssetBalance(owner, token);
+        let mut balance: AssetBalance = env.storage().instance()
+            .get(&balance_key)
+            .unwrap_or_else(|| panic!("Asset not held by this custody account"));
+
+        if balance.reserved < amount {
+            log!(&env, "Insufficient reserved balance to release");
+            return false;
+        }
+
+        balance.reserved -= amount;
+        balance.free += amount;
+
+        env.storage().instance().set(&balance_key, &balance);
+        env.storage().instance().extend_ttl(5000, 5000);
+
+        log!(&env, "Unreserved {}. Free balance: {}", amount, balance.free);
+        true
+    }
+
+    // Move free balance directly between two custody accounts. The sender is
+    // only ever debited if the recipient credit can also succeed.
+    pub fn transfer_assets(env: Env, from: Address, to: Address, token: Address, amount: i128) -> bool {
+        from.require_auth();
+
+        if amount <= 0 {
+            log!(&env, "Transfer amount must be positive");
+            return false;
+        }
+
+        let from_account: CustodyAccount = env.storage().instance()
+            .get(&CustodyBook::Account(from.clone()))
+            .unwrap_or_else(|| panic!("Custody account not found"));
+
+        if !from_account.is_active {
+            log!(&env, "Source custody account is not active");
+            return false;
+        }
+
+        let mut to_account: CustodyAccount = match env.storage().instance().get(&CustodyBook::Account(to.clone())) {
+            Some(account) => account,
+            None => {
+                log!(&env, "Destination custody account does not exist");
+                return false;
+            }
+        };
+
+        if !to_account.is_active {
+            log!(&env, "Destination custody account is not active");
+            return false;
+        }
+
+        let from_balance_key = CustodyBook::AssetBalance(from.clone(), token.clone());
+        let mut from_balance: AssetBalance = env.storage().instance()
+            .get(&from_balance_key)
+            .unwrap_or_else(|| panic!("Asset not held by source custody account"));
+
+        if from_balance.free < amount {
+            log!(&env, "Insufficient free balance for transfer");
+            return false;
+        }
+
+        if Self::track_asset(&env, &mut to_account, &token) {
+            env.storage().instance().set(&CustodyBook::Account(to.clone()), &to_account);
+        }
+
+        let to_balance_key = CustodyBook::AssetBalance(to, token);
+        let mut to_balance: AssetBalance = env.storage().instance()
+            .get(&to_balance_key)
+            .unwrap_or_else(AssetBalance::zero);
+
+        from_balance.free -= amount;
+        to_balance.free += amount;
+
+        env.storage().instance().set(&from_balance_key, &from_balance);
+        env.storage().instance().set(&to_balance_key, &to_balance);
+        env.storage().instance().extend_ttl(5000, 5000);
+
+        log!(&env, "Transferred {} from custody account to custody account", amount);
+        true
+    }
+
+    // Lock part of the free balance until `unlock_timestamp`, giving depositors
+    // escrow/vesting semantics directly in the custody account.
+    pub fn lock_assets(env: Env, owner: Address, token: Address, amount: i128, unlock_timestamp: u64) -> bool {
+        owner.require_auth();
+
+        if amount <= 0 {
+            log!(&env, "Lock amount must be positive");
+            return false;
+        }
+
+        if unlock_timestamp <= env.ledger().timestamp() {
+            log!(&env, "Unlock timestamp must be in the future");
+            return false;
+        }
+
+        let account: CustodyAccount = env.storage().instance()
+            .get(&CustodyBook::Account(owner.clone()))
+            .unwrap_or_else(|| panic!("Custody account not found"));
+
+        if !account.is_active {
+            log!(&env, "Custody account is not active");
+            return false;
+        }
+
+        let balance_key = CustodyBook::AssetBalance(owner, token);
+        let mut balance: AssetBalance = env.storage().instance()
+            .get(&balance_key)
+            .unwrap_or_else(|| panic!("Asset not held by this custody account"));
+
+        if balance.free < amount {
+            log!(&env, "Insufficient free balance to lock");
+            return false;
+        }
+
+        // A new lock can only extend the maturity of an existing one, never
+        // pull it earlier, so topping up a lock can't shorten its vesting
+        if balance.locked > 0 && unlock_timestamp < balance.unlock_timestamp {
+            log!(&env, "New unlock timestamp cannot be earlier than the existing lock's");
+            return false;
+        }
+
+        balance.free -= amount;
+        balance.locked += amount;
+        balance.unlock_timestamp = unlock_timestamp;
+
+        env.storage().instance().set(&balance_key, &balance);
         env.storage().instance().extend_ttl(5000, 5000);
-        
-        log!(&env, "Assets withdrawn successfully. Remaining balance: {}", account.balance);
+
+        log!(&env, "Locked {} until timestamp {}", amount, unlock_timestamp);
         true
     }
-    
-    // Function to view custody account details
+
+    // Release the locked portion back into the free balance once it has matured
+    pub fn unlock_assets(env: Env, owner: Address, token: Address) -> bool {
+        owner.require_auth();
+
+        let balance_key = CustodyBook::AssetBalance(owner, token);
+        let mut balance: AssetBalance = env.storage().instance()
+            .get(&balance_key)
+            .unwrap_or_else(|| panic!("Asset not held by this custody account"));
+
+        if balance.locked <= 0 {
+            log!(&env, "Nothing is locked for this asset");
+            return false;
+        }
+
+        if env.ledger().timestamp() < balance.unlock_timestamp {
+            log!(&env, "Locked assets are not unlockable yet");
+            return false;
+        }
+
+        balance.free += balance.locked;
+        balance.locked = 0;
+        balance.unlock_timestamp = 0;
+
+        env.storage().instance().set(&balance_key, &balance);
+        env.storage().instance().extend_ttl(5000, 5000);
+
+        log!(&env, "Unlocked assets. New free balance: {}", balance.free);
+        true
+    }
+
+    // File an insurance claim against a token's pool. Requires the account to
+    // be insured; payout only happens once enough signers approve the claim.
+    pub fn file_claim(env: Env, owner: Address, token: Address, amount: i128, reason: String) -> u64 {
+        owner.require_auth();
+
+        if amount <= 0 {
+            panic!("Claim amount must be positive");
+        }
+
+        let account: CustodyAccount = env.storage().instance()
+            .get(&CustodyBook::Account(owner.clone()))
+            .unwrap_or_else(|| panic!("Custody account not found"));
+
+        if !account.is_insured {
+            panic!("Custody account is not insured");
+        }
+
+        let mut claim_id: u64 = env.storage().instance().get(&CLAIM_COUNT).unwrap_or(0);
+        claim_id += 1;
+        env.storage().instance().set(&CLAIM_COUNT, &claim_id);
+
+        let claim = Claim {
+            id: claim_id,
+            owner,
+            token,
+            amount,
+            reason,
+            approvals: Vec::new(&env),
+            status: ClaimStatus::Pending,
+        };
+
+        env.storage().instance().set(&CustodyBook::Claim(claim_id), &claim);
+        env.storage().instance().extend_ttl(5000, 5000);
+
+        log!(&env, "Insurance claim {} filed", claim_id);
+        claim_id
+    }
+
+    // A registered signer on the claimant's account approves a pending claim
+    pub fn approve_claim(env: Env, signer: Address, claim_id: u64) {
+        signer.require_auth();
+
+        let claim_key = CustodyBook::Claim(claim_id);
+        let mut claim: Claim = env.storage().instance()
+            .get(&claim_key)
+            .unwrap_or_else(|| panic!("Insurance claim not found"));
+
+        if claim.status != ClaimStatus::Pending {
+            panic!("Insurance claim is no longer pending");
+        }
+
+        let account: CustodyAccount = env.storage().instance()
+            .get(&CustodyBook::Account(claim.owner.clone()))
+            .unwrap_or_else(|| panic!("Custody account not found"));
+
+        if !account.signers.contains(&signer) {
+            panic!("Signer is not authorized for this custody account");
+        }
+
+        if claim.approvals.contains(&signer) {
+            panic!("Signer has already approved this claim");
+        }
+
+        claim.approvals.push_back(signer);
+
+        if claim.approvals.len() >= account.required_signatures {
+            claim.status = ClaimStatus::Approved;
+        }
+
+        env.storage().instance().set(&claim_key, &claim);
+        env.storage().instance().extend_ttl(5000, 5000);
+
+        log!(&env, "Insurance claim {} now has {} approvals", claim_id, claim.approvals.len());
+    }
+
+    // Pay out an approved claim from the token's insurance pool into the
+    // claimant's balance for that same token
+    pub fn settle_claim(env: Env, claim_id: u64) -> bool {
+        let claim_key = CustodyBook::Claim(claim_id);
+        let mut claim: Claim = env.storage().instance()
+            .get(&claim_key)
+            .unwrap_or_else(|| panic!("Insurance claim not found"));
+
+        if claim.status != ClaimStatus::Approved {
+            log!(&env, "Insurance claim is not approved");
+            return false;
+        }
+
+        let pool_key = CustodyBook::InsurancePool(claim.token.clone());
+        let pool: i128 = env.storage().instance().get(&pool_key).unwrap_or(0);
+        if pool < claim.amount {
+            log!(&env, "Insurance pool cannot cover this claim");
+            return false;
+        }
+
+        let mut account: CustodyAccount = env.storage().instance()
+            .get(&CustodyBook::Account(claim.owner.clone()))
+            .unwrap_or_else(|| panic!("Custody account not found"));
+
+        if Self::track_asset(&env, &mut account, &claim.token) {
+            env.storage().instance().set(&CustodyBook::Account(claim.owner.clone()), &account);
+        }
+
+        let balance_key = CustodyBook::AssetBalance(claim.owner.clone(), claim.token.clone());
+        let mut balance: AssetBalance = env.storage().instance()
+            .get(&balance_key)
+            .unwrap_or_else(AssetBalance::zero);
+
+        balance.free += claim.amount;
+        claim.status = ClaimStatus::Settled;
+
+        env.storage().instance().set(&pool_key, &(pool - claim.amount));
+        env.storage().instance().set(&balance_key, &balance);
+        env.storage().instance().set(&claim_key, &claim);
+        env.storage().instance().extend_ttl(5000, 5000);
+
+        log!(&env, "Insurance claim {} settled for {}", claim_id, claim.amount);
+        true
+    }
+
+    // Basis-point premium owed on a deposit/withdrawal amount for insured accounts
+    fn premium_amount(amount: i128) -> i128 {
+        amount * PREMIUM_BPS / 10000
+    }
+
+    // Add a collected premium into the given token's insurance pool running total
+    fn accrue_premium(env: &Env, token: &Address, premium: i128) {
+        let pool_key = CustodyBook::InsurancePool(token.clone());
+        let pool: i128 = env.storage().instance().get(&pool_key).unwrap_or(0);
+        env.storage().instance().set(&pool_key, &(pool + premium));
+    }
+
+    // Add `token` to the account's held-asset list if it isn't already there.
+    // Returns true if the account was changed and needs to be re-stored.
+    fn track_asset(env: &Env, account: &mut CustodyAccount, token: &Address) -> bool {
+        if account.held_assets.contains(token) {
+            return false;
+        }
+        account.held_assets.push_back(token.clone());
+        env.storage().instance().set(&CustodyBook::AssetBalance(account.owner.clone(), token.clone()), &AssetBalance::zero());
+        true
+    }
+
+    // Function to view custody account configuration (signers, insurance, held assets)
     pub fn view_custody_account(env: Env, owner: Address) -> CustodyAccount {
         let account_key = CustodyBook::Account(owner.clone());
-        
+
         env.storage().instance().get(&account_key).unwrap_or(CustodyAccount {
             owner: owner.clone(),
-            balance: 0,
+            held_assets: Vec::new(&env),
+            signers: Vec::new(&env),
             required_signatures: 0,
             is_insured: false,
             is_active: false,
+            liquidation_start_time: 0,
+            existential_deposit: 0,
         })
     }
-}
\ No newline at end of file
+
+    // Function to view a custody account's balance in a single token
+    pub fn view_asset_balance(env: Env, owner: Address, token: Address) -> AssetBalance {
+        env.storage().instance()
+            .get(&CustodyBook::AssetBalance(owner, token))
+            .unwrap_or_else(AssetBalance::zero)
+    }
+
+    // Propose freezing an account pending liquidation. Follows the same
+    // asynchronous propose/approve/execute flow as a withdrawal proposal
+    // rather than requiring every signer to co-sign a single transaction.
+    pub fn propose_freeze(env: Env, proposer: Address, owner: Address) -> u64 {
+        proposer.require_auth();
+
+        let account: CustodyAccount = env.storage().instance()
+            .get(&CustodyBook::Account(owner.clone()))
+            .unwrap_or_else(|| panic!("Custody account not found"));
+
+        if !account.signers.contains(&proposer) {
+            panic!("Proposer is not authorized for this custody account");
+        }
+
+        if account.liquidation_start_time > 0 {
+            panic!("Custody account is already frozen");
+        }
+
+        let mut proposal_id: u64 = env.storage().instance().get(&FREEZE_PROPOSAL_COUNT).unwrap_or(0);
+        proposal_id += 1;
+        env.storage().instance().set(&FREEZE_PROPOSAL_COUNT, &proposal_id);
+
+        let proposal = FreezeProposal {
+            id: proposal_id,
+            account_owner: owner,
+            approvals: Vec::new(&env),
+            executed: false,
+            expires_at: env.ledger().timestamp() + PROPOSAL_LIFETIME,
+        };
+
+        env.storage().instance().set(&CustodyBook::FreezeProposal(proposal_id), &proposal);
+        env.storage().instance().extend_ttl(5000, 5000);
+
+        log!(&env, "Freeze proposal {} created", proposal_id);
+        proposal_id
+    }
+
+    // A registered signer approves a pending freeze proposal
+    pub fn approve_freeze(env: Env, signer: Address, proposal_id: u64) {
+        signer.require_auth();
+
+        let proposal_key = CustodyBook::FreezeProposal(proposal_id);
+        let mut proposal: FreezeProposal = env.storage().instance()
+            .get(&proposal_key)
+            .unwrap_or_else(|| panic!("Freeze proposal not found"));
+
+        if proposal.executed {
+            panic!("Freeze proposal already executed");
+        }
+
+        if env.ledger().timestamp() >= proposal.expires_at {
+            panic!("Freeze proposal has expired");
+        }
+
+        let account: CustodyAccount = env.storage().instance()
+            .get(&CustodyBook::Account(proposal.account_owner.clone()))
+            .unwrap_or_else(|| panic!("Custody account not found"));
+
+        if !account.signers.contains(&signer) {
+            panic!("Signer is not authorized for this custody account");
+        }
+
+        if proposal.approvals.contains(&signer) {
+            panic!("Signer has already approved this proposal");
+        }
+
+        proposal.approvals.push_back(signer);
+        env.storage().instance().set(&proposal_key, &proposal);
+        env.storage().instance().extend_ttl(5000, 5000);
+
+        log!(&env, "Freeze proposal {} now has {} approvals", proposal_id, proposal.approvals.len());
+    }
+
+    // Execute a freeze proposal once it has gathered enough signer approvals,
+    // blocking deposits and normal withdrawals. Nothing unfreezes the account
+    // afterwards; `execute_liquidation` sweeps it but leaves it frozen.
+    pub fn execute_freeze(env: Env, proposal_id: u64) -> bool {
+        let proposal_key = CustodyBook::FreezeProposal(proposal_id);
+        let mut proposal: FreezeProposal = env.storage().instance()
+            .get(&proposal_key)
+            .unwrap_or_else(|| panic!("Freeze proposal not found"));
+
+        if proposal.executed {
+            log!(&env, "Freeze proposal already executed");
+            return false;
+        }
+
+        if env.ledger().timestamp() >= proposal.expires_at {
+            log!(&env, "Freeze proposal has expired");
+            return false;
+        }
+
+        let account_key = CustodyBook::Account(proposal.account_owner.clone());
+        let mut account: CustodyAccount = env.storage().instance()
+            .get(&account_key)
+            .unwrap_or_else(|| panic!("Custody account not found"));
+
+        if account.liquidation_start_time > 0 {
+            log!(&env, "Custody account is already frozen");
+            return false;
+        }
+
+        if proposal.approvals.len() < account.required_signatures {
+            log!(&env, "Insufficient approvals. Required: {}, Got: {}",
+                account.required_signatures, proposal.approvals.len());
+            return false;
+        }
+
+        // A start time of 0 means "not frozen", so a freeze at ledger time 0
+        // is recorded as 1
+        account.liquidation_start_time = env.ledger().timestamp().max(1);
+        account.is_active = false;
+        proposal.executed = true;
+
+        env.storage().instance().set(&account_key, &account);
+        env.storage().instance().set(&proposal_key, &proposal);
+        env.storage().instance().extend_ttl(5000, 5000);
+
+        log!(&env, "Freeze proposal {} executed, account frozen for liquidation", proposal_id);
+        true
+    }
+
+    // Propose sweeping a frozen account's balances to a recovery address.
+    // The account must already be frozen via an executed freeze proposal.
+    pub fn propose_liquidation(env: Env, proposer: Address, owner: Address, to: Address) -> u64 {
+        proposer.require_auth();
+
+        let account: CustodyAccount = env.storage().instance()
+            .get(&CustodyBook::Account(owner.clone()))
+            .unwrap_or_else(|| panic!("Custody account not found"));
+
+        if !account.signers.contains(&proposer) {
+            panic!("Proposer is not authorized for this custody account");
+        }
+
+        if account.liquidation_start_time == 0 {
+            panic!("Custody account must be frozen before it can be liquidated");
+        }
+
+        let mut proposal_id: u64 = env.storage().instance().get(&LIQUIDATION_PROPOSAL_COUNT).unwrap_or(0);
+        proposal_id += 1;
+        env.storage().instance().set(&LIQUIDATION_PROPOSAL_COUNT, &proposal_id);
+
+        let proposal = LiquidationProposal {
+            id: proposal_id,
+            account_owner: owner,
+            to,
+            approvals: Vec::new(&env),
+            executed: false,
+            expires_at: env.ledger().timestamp() + PROPOSAL_LIFETIME,
+        };
+
+        env.storage().instance().set(&CustodyBook::LiquidationProposal(proposal_id), &proposal);
+        env.storage().instance().extend_ttl(5000, 5000);
+
+        log!(&env, "Liquidation proposal {} created", proposal_id);
+        proposal_id
+    }
+
+    // A registered signer approves a pending liquidation proposal
+    pub fn approve_liquidation(env: Env, signer: Address, proposal_id: u64) {
+        signer.require_auth();
+
+        let proposal_key = CustodyBook::LiquidationProposal(proposal_id);
+        let mut proposal: LiquidationProposal = env.storage().instance()
+            .get(&proposal_key)
+            .unwrap_or_else(|| panic!("Liquidation proposal not found"));
+
+        if proposal.executed {
+            panic!("Liquidation proposal already executed");
+        }
+
+        if env.ledger().timestamp() >= proposal.expires_at {
+            panic!("Liquidation proposal has expired");
+        }
+
+        let account: CustodyAccount = env.storage().instance()
+            .get(&CustodyBook::Account(proposal.account_owner.clone()))
+            .unwrap_or_else(|| panic!("Custody account not found"));
+
+        if !account.signers.contains(&signer) {
+            panic!("Signer is not authorized for this custody account");
+        }
+
+        if proposal.approvals.contains(&signer) {
+            panic!("Signer has already approved this proposal");
+        }
+
+        proposal.approvals.push_back(signer);
+        env.storage().instance().set(&proposal_key, &proposal);
+        env.storage().instance().extend_ttl(5000, 5000);
+
+        log!(&env, "Liquidation proposal {} now has {} approvals", proposal_id, proposal.approvals.len());
+    }
+
+    // Execute a liquidation proposal once it has gathered enough signer
+    // approvals and the timelock since freezing has elapsed, sweeping every
+    // token balance held by the frozen account to the recovery address
+    pub fn execute_liquidation(env: Env, proposal_id: u64) -> bool {
+        let proposal_key = CustodyBook::LiquidationProposal(proposal_id);
+        let mut proposal: LiquidationProposal = env.storage().instance()
+            .get(&proposal_key)
+            .unwrap_or_else(|| panic!("Liquidation proposal not found"));
+
+        if proposal.executed {
+            log!(&env, "Liquidation proposal already executed");
+            return false;
+        }
+
+        if env.ledger().timestamp() >= proposal.expires_at {
+            log!(&env, "Liquidation proposal has expired");
+            return false;
+        }
+
+        let account: CustodyAccount = env.storage().instance()
+            .get(&CustodyBook::Account(proposal.account_owner.clone()))
+            .unwrap_or_else(|| panic!("Custody account not found"));
+
+        if account.liquidation_start_time == 0 {
+            log!(&env, "Custody account must be frozen before it can be liquidated");
+            return false;
+        }
+
+        if env.ledger().timestamp() < account.liquidation_start_time + LIQUIDATION_TIMELOCK {
+            log!(&env, "Liquidation timelock has not elapsed yet");
+            return false;
+        }
+
+        if proposal.approvals.len() < account.required_signatures {
+            log!(&env, "Insufficient approvals. Required: {}, Got: {}",
+                account.required_signatures, proposal.approvals.len());
+            return false;
+        }
+
+        let mut to_account: CustodyAccount = match env.storage().instance().get(&CustodyBook::Account(proposal.to.clone())) {
+            Some(recovery_account) => recovery_account,
+            None => {
+                log!(&env, "Recovery custody account does not exist");
+                return false;
+            }
+        };
+
+        for token in account.held_assets.iter() {
+            let balance_key = CustodyBook::AssetBalance(proposal.account_owner.clone(), token.clone());
+            let mut balance: AssetBalance = env.storage().instance()
+                .get(&balance_key)
+                .unwrap_or_else(AssetBalance::zero);
+
+            let swept = balance.free + balance.reserved + balance.locked;
+            balance.free = 0;
+            balance.reserved = 0;
+            balance.locked = 0;
+            balance.unlock_timestamp = 0;
+            env.storage().instance().set(&balance_key, &balance);
+
+            if swept > 0 {
+                if Self::track_asset(&env, &mut to_account, &token) {
+                    env.storage().instance().set(&CustodyBook::Account(proposal.to.clone()), &to_account);
+                }
+                let to_balance_key = CustodyBook::AssetBalance(proposal.to.clone(), token.clone());
+                let mut to_balance: AssetBalance = env.storage().instance()
+                    .get(&to_balance_key)
+                    .unwrap_or_else(AssetBalance::zero);
+                to_balance.free += swept;
+                env.storage().instance().set(&to_balance_key, &to_balance);
+            }
+        }
+
+        proposal.executed = true;
+        env.storage().instance().set(&proposal_key, &proposal);
+        env.storage().instance().extend_ttl(5000, 5000);
+
+        log!(&env, "Liquidation proposal {} executed, account swept to recovery address", proposal_id);
+        true
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::testutils::Ledger as _;
+
+    #[test]
+    fn withdrawal_proposal_requires_full_approval_before_executing() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, AssetCustodyContract);
+        let client = AssetCustodyContractClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        let signer_a = Address::generate(&env);
+        let signer_b = Address::generate(&env);
+        let token = Address::generate(&env);
+        let recipient = Address::generate(&env);
+
+        let signers = Vec::from_array(&env, [signer_a.clone(), signer_b.clone()]);
+        client.create_custody_account(&owner, &signers, &2, &false, &DEFAULT_EXISTENTIAL_DEPOSIT);
+        client.register_asset(&owner, &token);
+        client.deposit_assets(&owner, &token, &1_000);
+
+        let proposal_id = client.propose_withdrawal(&owner, &token, &400, &recipient);
+
+        // A single approval is not enough to satisfy the 2-signer threshold
+        client.approve_withdrawal(&signer_a, &proposal_id);
+        assert_eq!(client.execute_withdrawal(&proposal_id), false);
+
+        client.approve_withdrawal(&signer_b, &proposal_id);
+        assert_eq!(client.execute_withdrawal(&proposal_id), true);
+
+        let balance = client.view_asset_balance(&owner, &token);
+        assert_eq!(balance.free, 600);
+    }
+
+    #[test]
+    fn locked_assets_cannot_be_unlocked_before_maturity_and_resist_shortening() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, AssetCustodyContract);
+        let client = AssetCustodyContractClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        let signer_a = Address::generate(&env);
+        let signer_b = Address::generate(&env);
+        let token = Address::generate(&env);
+
+        let signers = Vec::from_array(&env, [signer_a, signer_b]);
+        client.create_custody_account(&owner, &signers, &2, &false, &DEFAULT_EXISTENTIAL_DEPOSIT);
+        client.register_asset(&owner, &token);
+        client.deposit_assets(&owner, &token, &1_000);
+
+        let now = env.ledger().timestamp();
+        client.lock_assets(&owner, &token, &500, &(now + 1_000));
+
+        // Topping up with an earlier unlock timestamp must not shorten the lock
+        assert_eq!(client.lock_assets(&owner, &token, &100, &(now + 10)), false);
+
+        // Maturity hasn't arrived yet
+        assert_eq!(client.unlock_assets(&owner, &token), false);
+
+        env.ledger().with_mut(|ledger| {
+            ledger.timestamp = now + 1_001;
+        });
+
+        assert_eq!(client.unlock_assets(&owner, &token), true);
+        let balance = client.view_asset_balance(&owner, &token);
+        assert_eq!(balance.free, 1_000);
+        assert_eq!(balance.locked, 0);
+    }
+
+    #[test]
+    fn reserve_and_unreserve_roundtrip_and_dust_sweep_drops_the_asset() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, AssetCustodyContract);
+        let client = AssetCustodyContractClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        let signer_a = Address::generate(&env);
+        let signer_b = Address::generate(&env);
+        let token = Address::generate(&env);
+        let recipient = Address::generate(&env);
+
+        let signers = Vec::from_array(&env, [signer_a.clone(), signer_b.clone()]);
+        client.create_custody_account(&owner, &signers, &2, &false, &50);
+        client.register_asset(&owner, &token);
+        client.deposit_assets(&owner, &token, &1_000);
+
+        // Happy path: reserve then release back into free
+        assert_eq!(client.reserve(&owner, &token, &400), true);
+        let balance = client.view_asset_balance(&owner, &token);
+        assert_eq!(balance.free, 600);
+        assert_eq!(balance.reserved, 400);
+
+        assert_eq!(client.unreserve(&owner, &token, &400), true);
+        let balance = client.view_asset_balance(&owner, &token);
+        assert_eq!(balance.free, 1_000);
+        assert_eq!(balance.reserved, 0);
+
+        // Rejection: can't release more than is actually reserved
+        client.reserve(&owner, &token, &100);
+        assert_eq!(client.unreserve(&owner, &token, &200), false);
+
+        // Withdrawing down below the existential deposit sweeps the dust and
+        // drops the token from held_assets rather than deactivating the account
+        client.unreserve(&owner, &token, &100);
+        let proposal_id = client.propose_withdrawal(&owner, &token, &960, &recipient);
+        client.approve_withdrawal(&signer_a, &proposal_id);
+        client.approve_withdrawal(&signer_b, &proposal_id);
+        assert_eq!(client.execute_withdrawal(&proposal_id), true);
+
+        let balance = client.view_asset_balance(&owner, &token);
+        assert_eq!(balance.free, 0);
+        let account = client.view_custody_account(&owner);
+        assert_eq!(account.held_assets.contains(&token), false);
+        assert_eq!(account.is_active, true);
+    }
+
+    #[test]
+    fn transfer_between_custody_accounts_moves_balance_and_tracks_the_asset() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, AssetCustodyContract);
+        let client = AssetCustodyContractClient::new(&env, &contract_id);
+
+        let from_owner = Address::generate(&env);
+        let to_owner = Address::generate(&env);
+        let signer_a = Address::generate(&env);
+        let signer_b = Address::generate(&env);
+        let token = Address::generate(&env);
+
+        let signers = Vec::from_array(&env, [signer_a, signer_b]);
+        client.create_custody_account(&from_owner, &signers, &2, &false, &DEFAULT_EXISTENTIAL_DEPOSIT);
+        client.create_custody_account(&to_owner, &signers, &2, &false, &DEFAULT_EXISTENTIAL_DEPOSIT);
+        client.register_asset(&from_owner, &token);
+        client.deposit_assets(&from_owner, &token, &1_000);
+
+        // Happy path: destination picks up the asset and the balance moves over
+        assert_eq!(client.transfer_assets(&from_owner, &to_owner, &token, &300), true);
+        let from_balance = client.view_asset_balance(&from_owner, &token);
+        assert_eq!(from_balance.free, 700);
+        let to_balance = client.view_asset_balance(&to_owner, &token);
+        assert_eq!(to_balance.free, 300);
+        let to_account = client.view_custody_account(&to_owner);
+        assert_eq!(to_account.held_assets.contains(&token), true);
+
+        // Rejection: a transfer that would overdraw the source is refused and
+        // leaves both balances untouched
+        assert_eq!(client.transfer_assets(&from_owner, &to_owner, &token, &10_000), false);
+        let from_balance = client.view_asset_balance(&from_owner, &token);
+        assert_eq!(from_balance.free, 700);
+        let to_balance = client.view_asset_balance(&to_owner, &token);
+        assert_eq!(to_balance.free, 300);
+    }
+
+    #[test]
+    fn insurance_claim_only_pays_out_once_approved() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, AssetCustodyContract);
+        let client = AssetCustodyContractClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        let signer_a = Address::generate(&env);
+        let signer_b = Address::generate(&env);
+        let token = Address::generate(&env);
+
+        let signers = Vec::from_array(&env, [signer_a.clone(), signer_b.clone()]);
+        client.create_custody_account(&owner, &signers, &2, &true, &DEFAULT_EXISTENTIAL_DEPOSIT);
+        client.register_asset(&owner, &token);
+        // A 100_000 deposit accrues a 100-unit premium (10 bps) into the pool
+        client.deposit_assets(&owner, &token, &100_000);
+        let balance = client.view_asset_balance(&owner, &token);
+        assert_eq!(balance.free, 99_900);
+
+        let reason = String::from_str(&env, "stolen signing key");
+        let claim_id = client.file_claim(&owner, &token, &50, &reason);
+
+        // Rejection: settling before the claim is approved pays nothing out
+        assert_eq!(client.settle_claim(&claim_id), false);
+
+        client.approve_claim(&signer_a, &claim_id);
+        client.approve_claim(&signer_b, &claim_id);
+
+        // Happy path: an approved claim is settled from the token's pool
+        assert_eq!(client.settle_claim(&claim_id), true);
+        let balance = client.view_asset_balance(&owner, &token);
+        assert_eq!(balance.free, 99_950);
+    }
+
+    #[test]
+    fn account_tracks_multiple_assets_independently() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, AssetCustodyContract);
+        let client = AssetCustodyContractClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        let signer_a = Address::generate(&env);
+        let signer_b = Address::generate(&env);
+        let token_a = Address::generate(&env);
+        let token_b = Address::generate(&env);
+
+        let signers = Vec::from_array(&env, [signer_a, signer_b]);
+        client.create_custody_account(&owner, &signers, &2, &false, &DEFAULT_EXISTENTIAL_DEPOSIT);
+
+        // Happy path: registering distinct assets tracks both independently
+        client.register_asset(&owner, &token_a);
+        client.register_asset(&owner, &token_b);
+        client.deposit_assets(&owner, &token_a, &1_000);
+        client.deposit_assets(&owner, &token_b, &500);
+
+        let account = client.view_custody_account(&owner);
+        assert_eq!(account.held_assets.len(), 2);
+        assert_eq!(account.held_assets.contains(&token_a), true);
+        assert_eq!(account.held_assets.contains(&token_b), true);
+        assert_eq!(client.view_asset_balance(&owner, &token_a).free, 1_000);
+        assert_eq!(client.view_asset_balance(&owner, &token_b).free, 500);
+
+        // Rejection: registering the same asset twice is a no-op, not a duplicate entry
+        assert_eq!(client.register_asset(&owner, &token_a), false);
+        let account = client.view_custody_account(&owner);
+        assert_eq!(account.held_assets.len(), 2);
+    }
+
+    #[test]
+    fn freeze_then_liquidate_requires_full_approval_and_the_liquidation_timelock() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, AssetCustodyContract);
+        let client = AssetCustodyContractClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        let recovery_owner = Address::generate(&env);
+        let signer_a = Address::generate(&env);
+        let signer_b = Address::generate(&env);
+        let token = Address::generate(&env);
+
+        let signers = Vec::from_array(&env, [signer_a.clone(), signer_b.clone()]);
+        client.create_custody_account(&owner, &signers, &2, &false, &DEFAULT_EXISTENTIAL_DEPOSIT);
+        client.create_custody_account(&recovery_owner, &signers, &2, &false, &DEFAULT_EXISTENTIAL_DEPOSIT);
+        client.register_asset(&owner, &token);
+        client.deposit_assets(&owner, &token, &1_000);
+
+        let freeze_id = client.propose_freeze(&signer_a, &owner);
+
+        // Rejection: a single approval is not enough to execute the freeze
+        client.approve_freeze(&signer_a, &freeze_id);
+        assert_eq!(client.execute_freeze(&freeze_id), false);
+
+        client.approve_freeze(&signer_b, &freeze_id);
+        assert_eq!(client.execute_freeze(&freeze_id), true);
+        assert_eq!(client.view_custody_account(&owner).is_active, false);
+
+        let liquidation_id = client.propose_liquidation(&signer_a, &owner, &recovery_owner);
+        client.approve_liquidation(&signer_a, &liquidation_id);
+        client.approve_liquidation(&signer_b, &liquidation_id);
+
+        // Rejection: the liquidation timelock has not elapsed yet
+        assert_eq!(client.execute_liquidation(&liquidation_id), false);
+
+        env.ledger().with_mut(|ledger| {
+            ledger.timestamp += LIQUIDATION_TIMELOCK + 1;
+        });
+
+        // Happy path: once the timelock elapses the balance sweeps to recovery
+        assert_eq!(client.execute_liquidation(&liquidation_id), true);
+        assert_eq!(client.view_asset_balance(&owner, &token).free, 0);
+        assert_eq!(client.view_asset_balance(&recovery_owner, &token).free, 1_000);
+    }
+}